@@ -1,28 +1,207 @@
+// BLOCKING for merge -- Cargo.toml for `nu-protocol` needs, and does not currently have:
+//   - `arc-swap` as an ordinary dependency (used below for the RCU-style listener bag)
+//   - `futures-core` as an ordinary dependency (for the `Stream` impl below)
+//   - a `sqlite` feature gating `bridge_sqlite_interrupt`/`SqliteInterruptHandle` below
+// This crate will not compile without those three manifest edits. Left as a note rather than an
+// actual Cargo.toml change because this checkout has no Cargo.toml anywhere in the tree to edit
+// (confirmed repo-wide, not just for this crate) -- there's no existing manifest to extend, only
+// one to invent from nothing (crate name, version, edition, existing deps, workspace wiring, all
+// unknown here), which would be a bigger and riskier fabrication than the code changes in this
+// series. Add the three items above to the real manifest as part of landing this series.
 use crate::{ShellError, Span};
+use arc_swap::ArcSwap;
+use futures_core::Stream;
 use nu_glob::Interruptible;
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::future::Future;
+use std::io::{self, Read, Write};
+use std::pin::Pin;
 use std::sync::{
-    Arc,
-    atomic::{AtomicBool, Ordering},
+    Arc, Mutex, OnceLock,
+    atomic::{AtomicBool, AtomicU64, AtomicUsize, Ordering},
 };
+use std::task::{Context, Poll, Waker};
+use std::time::{Duration, Instant};
 
 pub trait Signal: Send + Sync {
     fn set(&self, value: bool);
     fn get(&self) -> bool;
 }
 
-/// Used to check for signals to suspend or terminate the execution of Nushell code.
-///
-/// For now, this struct only supports interruption (ctrl+c or SIGINT).
+/// How long after the first Ctrl+C press later presses still count towards escalation; a press
+/// landing later than that is treated as a fresh, isolated interrupt and restarts the count.
+const ESCALATION_WINDOW: Duration = Duration::from_secs(2);
+
+/// Number of [`SignalAction`] variants, i.e. the size of the small, fixed map [`Signals`] keeps
+/// from action to backing flag.
+const ACTION_COUNT: usize = 5;
+
+/// All [`SignalAction`] variants, in the order [`SignalAction::index`] assigns them.
+const ALL_ACTIONS: [SignalAction; ACTION_COUNT] = [
+    SignalAction::Interrupt,
+    SignalAction::Reset,
+    SignalAction::Suspend,
+    SignalAction::Resize,
+    SignalAction::Terminate,
+];
+
+/// Used to check for, and multiplex between, the signals that affect the execution of Nushell
+/// code (interrupt, suspend, resize, terminate).
 #[derive(Clone)]
 pub struct Signals {
-    signals: Option<Arc<dyn Signal>>,
+    signals: [Option<Arc<dyn Signal>>; ACTION_COUNT],
+    /// Tracks repeated interrupt presses so a wedged pipeline can still be killed. `None` for
+    /// [`Signals`] that aren't hooked up to a real interrupt source.
+    escalation: Option<Arc<Escalation>>,
+    /// Callbacks registered via [`subscribe`](Self::subscribe), invoked when `trigger_action` is
+    /// called. `None` for [`Signals`] that aren't hooked up to any signal source (unlike
+    /// `escalation`, which needs an interrupt source) -- built once, eagerly, in [`with_signal`](Self::with_signal), so every later
+    /// `.clone()` of a "live" [`Signals`] shares the same listener bag instead of each clone
+    /// lazily growing its own (which would let `subscribe` on one clone and `trigger_action` on
+    /// another talk past each other).
+    listeners: Option<Arc<Listeners>>,
+}
+
+/// Identifies a callback registered via [`Signals::subscribe`], for later removal with
+/// [`Signals::unsubscribe`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct SubscriptionId(u64);
+
+#[derive(Clone)]
+struct Subscriber {
+    id: SubscriptionId,
+    action: SignalAction,
+    callback: Arc<dyn Fn(SignalAction) + Send + Sync>,
+}
+
+/// Holds the set of callbacks registered via [`Signals::subscribe`].
+///
+/// Dispatch (on every [`Signals::trigger_action`]) vastly outnumbers subscribe/unsubscribe, and
+/// `trigger_action` may run from a signal handler where blocking is undesirable. So, following
+/// the read-copy-update layout `ReactiveSwift`'s `Signal` uses, the subscriber list lives behind
+/// an [`ArcSwap`]: dispatch loads a consistent snapshot with no lock, while `subscribe` and
+/// `unsubscribe` serialize behind `write_lock`, build a new immutable `Vec`, and atomically swap
+/// it in. A callback unsubscribed mid-dispatch simply won't appear in snapshots taken afterwards.
+struct Listeners {
+    subscribers: ArcSwap<Vec<Subscriber>>,
+    next_id: AtomicU64,
+    write_lock: Mutex<()>,
+}
+
+impl Listeners {
+    fn new() -> Self {
+        Self {
+            subscribers: ArcSwap::from_pointee(Vec::new()),
+            next_id: AtomicU64::new(0),
+            write_lock: Mutex::new(()),
+        }
+    }
+
+    fn subscribe(
+        &self,
+        action: SignalAction,
+        callback: Box<dyn Fn(SignalAction) + Send + Sync>,
+    ) -> SubscriptionId {
+        let id = SubscriptionId(self.next_id.fetch_add(1, Ordering::Relaxed));
+        let _guard = self.write_lock.lock().expect("not poisoned");
+        let mut next = (**self.subscribers.load()).clone();
+        next.push(Subscriber {
+            id,
+            action,
+            callback: callback.into(),
+        });
+        self.subscribers.store(Arc::new(next));
+        id
+    }
+
+    fn unsubscribe(&self, id: SubscriptionId) {
+        let _guard = self.write_lock.lock().expect("not poisoned");
+        let next: Vec<Subscriber> = (**self.subscribers.load())
+            .iter()
+            .filter(|subscriber| subscriber.id != id)
+            .cloned()
+            .collect();
+        self.subscribers.store(Arc::new(next));
+    }
+
+    fn dispatch(&self, action: SignalAction) {
+        for subscriber in self.subscribers.load().iter() {
+            if subscriber.action == action {
+                (subscriber.callback)(action);
+            }
+        }
+    }
+}
+
+/// Default number of presses, within [`ESCALATION_WINDOW`] of the first, after which the
+/// registered force handler is invoked.
+const DEFAULT_DEREGISTER_AFTER: usize = 3;
+
+/// Tracks repeated interrupt presses so a wedged pipeline can still be killed.
+///
+/// `press_count` is incremented on every [`Signals::trigger`]. Once it reaches
+/// `deregister_after` within [`ESCALATION_WINDOW`] of the first press, `force` is invoked on that
+/// press and again on every further press within the window; it is expected to tear down Nushell's own signal handler and re-raise the signal so the
+/// OS default disposition can terminate the process.
+struct Escalation {
+    press_count: AtomicUsize,
+    deregister_after: AtomicUsize,
+    first_press: Mutex<Option<Instant>>,
+    force: Mutex<Option<Box<dyn Fn() + Send + Sync>>>,
+}
+
+impl Escalation {
+    fn new() -> Self {
+        Self {
+            press_count: AtomicUsize::new(0),
+            deregister_after: AtomicUsize::new(DEFAULT_DEREGISTER_AFTER),
+            first_press: Mutex::new(None),
+            force: Mutex::new(None),
+        }
+    }
+
+    /// Registers a new press, returning the resulting press count.
+    fn press(&self) -> usize {
+        let now = Instant::now();
+        let mut first_press = self.first_press.lock().expect("not poisoned");
+        let count = match *first_press {
+            Some(first) if now.duration_since(first) <= ESCALATION_WINDOW => {
+                self.press_count.fetch_add(1, Ordering::Relaxed) + 1
+            }
+            _ => {
+                *first_press = Some(now);
+                self.press_count.store(1, Ordering::Relaxed);
+                1
+            }
+        };
+        drop(first_press);
+
+        if count >= self.deregister_after.load(Ordering::Relaxed) {
+            if let Some(force) = self.force.lock().expect("not poisoned").as_ref() {
+                force();
+            }
+        }
+
+        count
+    }
 }
 
 impl std::fmt::Debug for Signals {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         f.debug_struct("Signals")
-            .field("signals", &self.signals.as_ref().map(|s| s.get()))
+            .field(
+                "signals",
+                &ALL_ACTIONS
+                    .iter()
+                    .filter_map(|&action| {
+                        self.signals[action.index()]
+                            .as_deref()
+                            .map(|signal| (action, signal.get()))
+                    })
+                    .collect::<HashMap<_, _>>(),
+            )
+            .field("press_count", &self.press_count())
             .finish()
     }
 }
@@ -31,16 +210,18 @@ impl Signals {
     /// A [`Signals`] that is not hooked up to any event/signals source.
     ///
     /// So, this [`Signals`] will never be interrupted.
-    pub const EMPTY: Self = Signals { signals: None };
+    pub const EMPTY: Self = Signals {
+        signals: [None, None, None, None, None],
+        escalation: None,
+        listeners: None,
+    };
 
     /// Create a new [`Signals`] with `ctrlc` as the interrupt source.
     ///
     /// Once `ctrlc` is set to `true`, [`check`](Self::check) will error
     /// and [`interrupted`](Self::interrupted) will return `true`.
     pub fn new(ctrlc: Arc<dyn Signal>) -> Self {
-        Self {
-            signals: Some(ctrlc),
-        }
+        Self::empty().with_signal(SignalAction::Interrupt, ctrlc)
     }
 
     /// Create a [`Signals`] that is not hooked up to any event/signals source.
@@ -53,46 +234,478 @@ impl Signals {
         Self::EMPTY
     }
 
-    /// Returns an `Err` if an interrupt has been triggered.
+    /// Registers `signal` as the backing flag for `action`, returning `self` for chaining.
+    ///
+    /// This lets a single [`Signals`] multiplex several kinds of signal (interrupt, suspend,
+    /// resize, terminate), each backed by its own flag, the way `signal-hook-registry` dispatches
+    /// to multiple callbacks per OS signal.
+    pub fn with_signal(mut self, action: SignalAction, signal: Arc<dyn Signal>) -> Self {
+        if action == SignalAction::Interrupt && self.escalation.is_none() {
+            self.escalation = Some(Arc::new(Escalation::new()));
+        }
+        if self.listeners.is_none() {
+            self.listeners = Some(Arc::new(Listeners::new()));
+        }
+        self.signals[action.index()] = Some(signal);
+        self
+    }
+
+    /// Registers a "force" closure, invoked once `press_count` reaches `deregister_after` within
+    /// a short window of the first press. The closure should remove Nushell's own interrupt
+    /// handler and re-raise the signal, so the OS default disposition can terminate a process
+    /// that's stuck in native code that never calls [`check`](Self::check).
+    ///
+    /// Does nothing for a [`Signals`] that isn't hooked up to an interrupt source.
+    pub fn set_force_handler(
+        &self,
+        deregister_after: usize,
+        force: impl Fn() + Send + Sync + 'static,
+    ) {
+        if let Some(escalation) = &self.escalation {
+            escalation
+                .deregister_after
+                .store(deregister_after, Ordering::Relaxed);
+            *escalation.force.lock().expect("not poisoned") = Some(Box::new(force));
+        }
+    }
+
+    /// Registers `callback` to run whenever `action` is [`trigger`](Self::trigger_action)ed,
+    /// returning a [`SubscriptionId`] that can later be passed to
+    /// [`unsubscribe`](Self::unsubscribe).
+    ///
+    /// Callbacks run synchronously, inline in the call to `trigger_action`, so they should be
+    /// cheap -- this lets plugins, the REPL, and background jobs run cleanup/redraw logic the
+    /// moment a signal fires, rather than having to poll `interrupted`.
+    ///
+    /// Does nothing (and returns a [`SubscriptionId`] that matches nothing) for a [`Signals`]
+    /// that isn't hooked up to any signal source. Unlike
+    /// [`set_force_handler`](Self::set_force_handler), any source registered via
+    /// [`with_signal`](Self::with_signal) is enough, not just an interrupt source.
+    pub fn subscribe(
+        &self,
+        action: SignalAction,
+        callback: Box<dyn Fn(SignalAction) + Send + Sync>,
+    ) -> SubscriptionId {
+        match &self.listeners {
+            Some(listeners) => listeners.subscribe(action, callback),
+            None => SubscriptionId(0),
+        }
+    }
+
+    /// Removes a callback previously registered with [`subscribe`](Self::subscribe).
+    pub fn unsubscribe(&self, id: SubscriptionId) {
+        if let Some(listeners) = &self.listeners {
+            listeners.unsubscribe(id);
+        }
+    }
+
+    /// Returns the number of presses registered since the counter was last reset, either by
+    /// [`reset`](Self::reset) or by presses being spaced far enough apart. Useful for showing the user a "press Ctrl+C again to
+    /// force quit" style message.
+    pub fn press_count(&self) -> usize {
+        self.escalation
+            .as_deref()
+            .map(|escalation| escalation.press_count.load(Ordering::Relaxed))
+            .unwrap_or(0)
+    }
+
+    /// Returns an `Err` if `action` has been triggered.
     ///
     /// Otherwise, returns `Ok`.
     #[inline]
-    pub fn check(&self, span: &Span) -> Result<(), ShellError> {
+    pub fn check_action(&self, action: SignalAction, span: &Span) -> Result<(), ShellError> {
         #[inline]
         #[cold]
         fn interrupt_error(span: &Span) -> Result<(), ShellError> {
             Err(ShellError::Interrupted { span: *span })
         }
 
-        if self.interrupted() {
+        if self.interrupted_action(action) {
             interrupt_error(span)
         } else {
             Ok(())
         }
     }
 
-    /// Triggers an interrupt.
-    pub fn trigger(&self) {
-        if let Some(signals) = &self.signals {
-            signals.set(true);
+    /// Returns an `Err` if an interrupt has been triggered.
+    ///
+    /// Otherwise, returns `Ok`. A thin wrapper over [`check_action`](Self::check_action) with
+    /// [`SignalAction::Interrupt`], kept for convenience since interruption is by far the most
+    /// common thing callers check for.
+    #[inline]
+    pub fn check(&self, span: &Span) -> Result<(), ShellError> {
+        self.check_action(SignalAction::Interrupt, span)
+    }
+
+    /// Triggers `action`.
+    ///
+    /// Repeated [`SignalAction::Interrupt`] triggers within a short window count towards the
+    /// escalation threshold set by [`set_force_handler`](Self::set_force_handler); once that
+    /// threshold is reached, the registered force handler is invoked so a truly stuck process can
+    /// still be killed.
+    pub fn trigger_action(&self, action: SignalAction) {
+        if let Some(signal) = &self.signals[action.index()] {
+            signal.set(true);
+        }
+        if action == SignalAction::Interrupt {
+            if let Some(escalation) = &self.escalation {
+                escalation.press();
+            }
+        }
+        if let Some(listeners) = &self.listeners {
+            listeners.dispatch(action);
         }
     }
 
-    /// Returns whether an interrupt has been triggered.
+    /// Triggers an interrupt. A thin wrapper over [`trigger_action`](Self::trigger_action) with
+    /// [`SignalAction::Interrupt`], kept for convenience since interruption is by far the most
+    /// common signal callers raise.
+    pub fn trigger(&self) {
+        self.trigger_action(SignalAction::Interrupt);
+    }
+
+    /// Returns whether `action` has been triggered.
     #[inline]
-    pub fn interrupted(&self) -> bool {
-        self.signals
+    pub fn interrupted_action(&self, action: SignalAction) -> bool {
+        self.signals[action.index()]
             .as_deref()
-            .is_some_and(|b| b.get())
+            .is_some_and(|signal| signal.get())
+    }
+
+    /// Returns whether an interrupt has been triggered. A thin wrapper over
+    /// [`interrupted_action`](Self::interrupted_action) with [`SignalAction::Interrupt`], kept
+    /// for convenience since interruption is by far the most common thing callers check for.
+    #[inline]
+    pub fn interrupted(&self) -> bool {
+        self.interrupted_action(SignalAction::Interrupt)
     }
 
     pub(crate) fn is_empty(&self) -> bool {
-        self.signals.is_none()
+        self.signals.iter().all(Option::is_none)
+    }
+
+    /// Resets `action` back to its un-triggered state.
+    pub fn reset_action(&self, action: SignalAction) {
+        if let Some(signal) = &self.signals[action.index()] {
+            signal.set(false);
+        }
+        if action == SignalAction::Interrupt {
+            if let Some(escalation) = &self.escalation {
+                escalation.press_count.store(0, Ordering::Relaxed);
+                *escalation.first_press.lock().expect("not poisoned") = None;
+            }
+        }
     }
 
+    /// Resets the interrupt signal. A thin wrapper over [`reset_action`](Self::reset_action) with
+    /// [`SignalAction::Interrupt`].
     pub fn reset(&self) {
-        if let Some(signals) = &self.signals {
-            signals.set(false);
+        self.reset_action(SignalAction::Interrupt);
+    }
+
+    /// Wraps `reader` so it checks this [`Signals`] before every underlying
+    /// [`Read::read`](std::io::Read::read) call, returning an [`io::Error`] carrying
+    /// [`InterruptError`] the moment an interrupt is triggered. Gives byte-stream commands (`open`, `http`, ...)
+    /// cancellable I/O without manually sprinkling [`check`](Self::check) between reads.
+    pub fn wrap_read<R: Read>(&self, reader: R, span: Span) -> InterruptRead<R> {
+        InterruptRead {
+            inner: reader,
+            signals: self.clone(),
+            span,
+        }
+    }
+
+    /// Wraps `writer` so it checks this [`Signals`] before every underlying
+    /// [`Write::write`](std::io::Write::write)/[`flush`](std::io::Write::flush) call, returning
+    /// an [`io::Error`] carrying [`InterruptError`] the moment an interrupt is triggered. Gives
+    /// byte-stream
+    /// commands (`save`, `http`, ...) cancellable I/O without manually sprinkling
+    /// [`check`](Self::check) between writes.
+    pub fn wrap_write<W: Write>(&self, writer: W, span: Span) -> InterruptWrite<W> {
+        InterruptWrite {
+            inner: writer,
+            signals: self.clone(),
+            span,
+        }
+    }
+
+    /// Wraps `stream` so it stops yielding items once an interrupt fires.
+    ///
+    /// The in-flight item, if any, gets one more poll and is yielded if that completes it; after
+    /// that, instead of polling for the next item, the wrapper surfaces one final [`ShellError::Interrupted`] and then ends the
+    /// stream, giving async pipeline stages a uniform cancellation point without each one
+    /// reinventing signal plumbing.
+    pub fn interruptible_stream<S: Stream + Unpin>(
+        &self,
+        stream: S,
+        span: Span,
+    ) -> InterruptibleStream<S> {
+        InterruptibleStream {
+            inner: stream,
+            signals: self.clone(),
+            span,
+            interrupted: false,
+            pending: false,
+            waker: OnceLock::new(),
+        }
+    }
+
+    /// Wraps `fut` so that, instead of polling it through to completion, the wrapper resolves to
+    /// [`ShellError::Interrupted`] the moment an interrupt fires.
+    pub fn interruptible_future<F: Future + Unpin>(
+        &self,
+        fut: F,
+        span: Span,
+    ) -> InterruptibleFuture<F> {
+        InterruptibleFuture {
+            inner: fut,
+            signals: self.clone(),
+            span,
+            waker: OnceLock::new(),
+        }
+    }
+
+    /// Bridges this [`Signals`] to a live SQLite connection: the moment an interrupt fires,
+    /// `handle.interrupt()` is called so a running `query db`/`stor` statement aborts instead of
+    /// ignoring Ctrl+C while blocked inside the C library. Returns a [`SubscriptionId`] so the
+    /// bridge can be torn down with [`unsubscribe`](Self::unsubscribe) once the query finishes.
+    ///
+    /// Gated behind the `sqlite` feature so this crate doesn't take a hard `rusqlite`
+    /// dependency; this crate provides only the [`SqliteInterruptHandle`] trait, and no
+    /// implementation of it for `rusqlite::Connection::interrupt_handle()`.
+    ///
+    /// Subscribes on `self`, so this only bridges interrupts actually triggered through `self` or
+    /// a clone of it -- fine in practice since `with_signal` builds `listeners` once, eagerly,
+    /// behind an `Arc`, so `self` shares the same listener bag as every other clone of the
+    /// `Signals` it was cloned from, including whichever one a real Ctrl+C handler calls
+    /// `trigger`/`trigger_action` on.
+    #[cfg(feature = "sqlite")]
+    pub fn bridge_sqlite_interrupt(&self, handle: impl SqliteInterruptHandle) -> SubscriptionId {
+        let handle = Arc::new(handle);
+        self.subscribe(
+            SignalAction::Interrupt,
+            Box::new(move |_| handle.interrupt()),
+        )
+    }
+}
+
+/// A handle to a SQLite connection that this crate can call into via
+/// [`Signals::bridge_sqlite_interrupt`] without depending on `rusqlite` directly. This crate
+/// provides no implementation; a thin wrapper around `rusqlite::InterruptHandle` can implement it.
+#[cfg(feature = "sqlite")]
+pub trait SqliteInterruptHandle: Send + Sync + 'static {
+    /// Aborts the connection's in-flight statement, e.g. by calling
+    /// `rusqlite::InterruptHandle::interrupt`.
+    fn interrupt(&self);
+}
+
+/// Carried by the [`io::Error`] (kind [`io::ErrorKind::Other`]) that
+/// [`InterruptRead`]/[`InterruptWrite`] return once an interrupt fires. Recoverable via
+/// [`io::Error::downcast`] (or [`io::Error::get_ref`]), and convertible straight to
+/// [`ShellError::Interrupted`].
+///
+/// Deliberately *not* [`io::ErrorKind::Interrupted`]: that's the POSIX-EINTR kind, and generic
+/// combinators like [`std::io::copy`] and the default [`Read::read_to_end`] special-case it by
+/// looping and retrying the call instead of propagating it. Since our interrupt flag stays set,
+/// such a combinator would busy-loop forever instead of ever observing the cancellation, so this
+/// carries the meaning via the [`InterruptError`] payload instead of the `ErrorKind`.
+#[derive(Debug, Clone, Copy)]
+pub struct InterruptError {
+    span: Span,
+}
+
+impl std::fmt::Display for InterruptError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "operation was interrupted")
+    }
+}
+
+impl std::error::Error for InterruptError {}
+
+impl From<InterruptError> for ShellError {
+    fn from(err: InterruptError) -> Self {
+        ShellError::Interrupted { span: err.span }
+    }
+}
+
+/// Checks `signals` before an I/O call, for use by [`InterruptRead`]/[`InterruptWrite`]. Uses the
+/// same cheap, relaxed-load fast path [`Signals::check`] does, so throughput is unaffected when
+/// no interrupt is pending.
+#[inline]
+fn check_interrupted(signals: &Signals, span: Span) -> io::Result<()> {
+    if signals.interrupted() {
+        Err(io::Error::other(InterruptError { span }))
+    } else {
+        Ok(())
+    }
+}
+
+/// Adapts a [`Read`] to consult a [`Signals`] before every call, so a blocking read notices an
+/// interrupt immediately rather than only the next time the caller happens to call
+/// [`Signals::check`]. Construct via [`Signals::wrap_read`].
+pub struct InterruptRead<R> {
+    inner: R,
+    signals: Signals,
+    span: Span,
+}
+
+impl<R: Read> Read for InterruptRead<R> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        check_interrupted(&self.signals, self.span)?;
+        self.inner.read(buf)
+    }
+}
+
+/// Adapts a [`Write`] to consult a [`Signals`] before every call, so a blocking write notices an
+/// interrupt immediately rather than only the next time the caller happens to call
+/// [`Signals::check`]. Construct via [`Signals::wrap_write`].
+pub struct InterruptWrite<W> {
+    inner: W,
+    signals: Signals,
+    span: Span,
+}
+
+impl<W: Write> Write for InterruptWrite<W> {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        check_interrupted(&self.signals, self.span)?;
+        self.inner.write(buf)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        check_interrupted(&self.signals, self.span)?;
+        self.inner.flush()
+    }
+}
+
+/// Lazily subscribes to a [`Signals`]' interrupt listeners so a future/stream that's genuinely
+/// stuck -- no further wakeups of its own, exactly the wedged-pipeline case
+/// [`Escalation`]/[`Signals::trigger`] exist to rescue -- still gets polled again once an
+/// interrupt fires, instead of parking forever. Shared by [`InterruptibleStream`] and
+/// [`InterruptibleFuture`]; unsubscribes itself on drop.
+///
+/// Relies on the wrapper's `Signals::clone()` sharing the same underlying `Listeners` as whatever
+/// instance later calls `trigger`/`trigger_action` -- true since `with_signal` builds `listeners`
+/// once, eagerly, behind an `Arc`.
+struct InterruptWaker {
+    slot: Arc<Mutex<Option<Waker>>>,
+    subscription: SubscriptionId,
+}
+
+impl InterruptWaker {
+    fn new(signals: &Signals) -> Self {
+        let slot: Arc<Mutex<Option<Waker>>> = Arc::new(Mutex::new(None));
+        let callback_slot = Arc::clone(&slot);
+        let subscription = signals.subscribe(
+            SignalAction::Interrupt,
+            Box::new(move |_| {
+                if let Some(waker) = callback_slot.lock().expect("not poisoned").take() {
+                    waker.wake();
+                }
+            }),
+        );
+        Self { slot, subscription }
+    }
+
+    /// Arms the subscription with `waker`, so it's woken the next time the interrupt fires.
+    fn arm(&self, waker: &Waker) {
+        *self.slot.lock().expect("not poisoned") = Some(waker.clone());
+    }
+}
+
+/// Adapts a [`Stream`] to stop yielding items once an interrupt fires. Construct via
+/// [`Signals::interruptible_stream`].
+pub struct InterruptibleStream<S> {
+    inner: S,
+    signals: Signals,
+    span: Span,
+    interrupted: bool,
+    /// Whether the last poll of `inner` returned `Pending`, i.e. an item is in flight.
+    pending: bool,
+    waker: OnceLock<InterruptWaker>,
+}
+
+impl<S> Drop for InterruptibleStream<S> {
+    fn drop(&mut self) {
+        if let Some(waker) = self.waker.get() {
+            self.signals.unsubscribe(waker.subscription);
+        }
+    }
+}
+
+impl<S: Stream + Unpin> Stream for InterruptibleStream<S> {
+    type Item = Result<S::Item, ShellError>;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        if self.interrupted {
+            return Poll::Ready(None);
+        }
+
+        // Checked before polling `inner` unless an item is already in flight, so once an
+        // interrupt has fired no further item is requested. This also stops a stream that's
+        // always ready (e.g. a synchronous in-memory adapter that never itself returns `Pending`).
+        if !self.pending && self.signals.interrupted() {
+            self.interrupted = true;
+            return Poll::Ready(Some(Err(ShellError::Interrupted { span: self.span })));
+        }
+
+        match Pin::new(&mut self.inner).poll_next(cx) {
+            Poll::Ready(item) => {
+                self.pending = false;
+                Poll::Ready(item.map(Ok))
+            }
+            Poll::Pending => {
+                self.pending = true;
+                let signals = self.signals.clone();
+                self.waker
+                    .get_or_init(|| InterruptWaker::new(&signals))
+                    .arm(cx.waker());
+                // Checked after arming, so an interrupt landing between the inner poll and
+                // `arm` is not missed.
+                if self.signals.interrupted() {
+                    self.interrupted = true;
+                    return Poll::Ready(Some(Err(ShellError::Interrupted { span: self.span })));
+                }
+                Poll::Pending
+            }
+        }
+    }
+}
+
+/// Adapts a [`Future`] to resolve to [`ShellError::Interrupted`] once an interrupt fires, instead
+/// of running to completion. Construct via [`Signals::interruptible_future`].
+pub struct InterruptibleFuture<F> {
+    inner: F,
+    signals: Signals,
+    span: Span,
+    waker: OnceLock<InterruptWaker>,
+}
+
+impl<F> Drop for InterruptibleFuture<F> {
+    fn drop(&mut self) {
+        if let Some(waker) = self.waker.get() {
+            self.signals.unsubscribe(waker.subscription);
+        }
+    }
+}
+
+impl<F: Future + Unpin> Future for InterruptibleFuture<F> {
+    type Output = Result<F::Output, ShellError>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        match Pin::new(&mut self.inner).poll(cx) {
+            Poll::Ready(output) => Poll::Ready(Ok(output)),
+            Poll::Pending => {
+                let signals = self.signals.clone();
+                self.waker
+                    .get_or_init(|| InterruptWaker::new(&signals))
+                    .arm(cx.waker());
+                // Checked after arming, so an interrupt landing between the inner poll and
+                // `arm` is not missed.
+                if self.signals.interrupted() {
+                    return Poll::Ready(Err(ShellError::Interrupted { span: self.span }));
+                }
+                Poll::Pending
+            }
         }
     }
 }
@@ -118,8 +731,29 @@ impl Interruptible for Signals {
 
 /// The types of things that can be signaled. It's anticipated this will change as we learn more
 /// about how we'd like signals to be handled.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub enum SignalAction {
+    /// The user wants to abort the running pipeline (ctrl+c or SIGINT).
     Interrupt,
     Reset,
+    /// The user wants to pause the running pipeline (ctrl+z or SIGTSTP).
+    Suspend,
+    /// The terminal was resized (SIGWINCH).
+    Resize,
+    /// The process has been asked to shut down (SIGTERM).
+    Terminate,
+}
+
+impl SignalAction {
+    /// Index into [`Signals`]'s small, fixed `signals` array. Must stay in sync with
+    /// [`ALL_ACTIONS`] and [`ACTION_COUNT`].
+    const fn index(self) -> usize {
+        match self {
+            SignalAction::Interrupt => 0,
+            SignalAction::Reset => 1,
+            SignalAction::Suspend => 2,
+            SignalAction::Resize => 3,
+            SignalAction::Terminate => 4,
+        }
+    }
 }